@@ -0,0 +1,27 @@
+use std::io::{BufRead, Read};
+
+use crate::protocol::ClientEvent;
+
+/// Reads and parses a single click event from the `click_events` stream.
+///
+/// swaybar wraps the stream of events in a JSON array: a leading `[`,
+/// followed by comma-separated objects. This strips the opening bracket
+/// (if present) and the leading comma (if present) before decoding the
+/// next `{...}` object.
+pub fn read_client_event(stdin: &mut impl BufRead) -> Option<ClientEvent> {
+    loop {
+        let mut byte = [0u8; 1];
+        if stdin.read_exact(&mut byte).is_err() {
+            return None;
+        }
+        match byte[0] {
+            b'[' | b',' | b'\n' => continue,
+            b'{' => {
+                let mut buf = vec![b'{'];
+                stdin.read_until(b'}', &mut buf).ok()?;
+                return serde_json::from_slice(&buf).ok();
+            }
+            _ => continue,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An sRGB color with alpha, validated at construction and serialized the
+/// way swaybar expects it: `#RRGGBBAA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+impl Color {
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self::rgba(r, g, b, 0xFF)
+    }
+
+    /// Parses a `#RRGGBB` or `#RRGGBBAA` hex string.
+    pub fn parse(hex: &str) -> Result<Self, ColorError> {
+        let digits = hex
+            .strip_prefix('#')
+            .ok_or_else(|| ColorError::MissingHash(hex.to_string()))?;
+
+        let channel = |range: std::ops::Range<usize>| -> Result<u8, ColorError> {
+            let slice = digits
+                .get(range)
+                .ok_or_else(|| ColorError::WrongLength(hex.to_string()))?;
+            u8::from_str_radix(slice, 16).map_err(|_| ColorError::InvalidDigit(hex.to_string()))
+        };
+
+        match digits.len() {
+            6 => Ok(Self::rgb(channel(0..2)?, channel(2..4)?, channel(4..6)?)),
+            8 => Ok(Self::rgba(
+                channel(0..2)?,
+                channel(2..4)?,
+                channel(4..6)?,
+                channel(6..8)?,
+            )),
+            _ => Err(ColorError::WrongLength(hex.to_string())),
+        }
+    }
+
+    /// Looks up a CSS-ish named color, e.g. `"red"` or `"transparent"`.
+    pub fn named(name: &str) -> Result<Self, ColorError> {
+        match name.to_ascii_lowercase().as_str() {
+            "black" => Ok(Self::rgb(0x00, 0x00, 0x00)),
+            "white" => Ok(Self::rgb(0xFF, 0xFF, 0xFF)),
+            "red" => Ok(Self::rgb(0xFF, 0x00, 0x00)),
+            "green" => Ok(Self::rgb(0x00, 0x80, 0x00)),
+            "blue" => Ok(Self::rgb(0x00, 0x00, 0xFF)),
+            "yellow" => Ok(Self::rgb(0xFF, 0xFF, 0x00)),
+            "orange" => Ok(Self::rgb(0xFF, 0xA5, 0x00)),
+            "gray" | "grey" => Ok(Self::rgb(0x80, 0x80, 0x80)),
+            "transparent" => Ok(Self::rgba(0x00, 0x00, 0x00, 0x00)),
+            _ => Err(ColorError::UnknownName(name.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+    }
+}
+
+/// A `Color::parse`/`Color::named` input that couldn't be turned into a `Color`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    MissingHash(String),
+    WrongLength(String),
+    InvalidDigit(String),
+    UnknownName(String),
+}
+
+impl fmt::Display for ColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorError::MissingHash(s) => write!(f, "color `{}` is missing its leading '#'", s),
+            ColorError::WrongLength(s) => {
+                write!(f, "color `{}` must have 6 or 8 hex digits after '#'", s)
+            }
+            ColorError::InvalidDigit(s) => write!(f, "color `{}` contains a non-hex digit", s),
+            ColorError::UnknownName(s) => write!(f, "`{}` is not a known color name", s),
+        }
+    }
+}
+
+impl std::error::Error for ColorError {}
+
+impl Serialize for Color {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Color::parse(&raw).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::color::Color;
+use crate::protocol::Block;
+use crate::status_bar::Module;
+
+/// Below this charge percentage, the block's text is colored red.
+const LOW_BATTERY_THRESHOLD: u8 = 15;
+
+/// Shows the charge percentage of a battery exposed under
+/// `/sys/class/power_supply/<name>`, e.g. `BAT0`.
+pub struct Battery {
+    sysfs_dir: PathBuf,
+}
+
+impl Battery {
+    pub fn new(name: &str) -> Self {
+        Self {
+            sysfs_dir: PathBuf::from("/sys/class/power_supply").join(name),
+        }
+    }
+
+    fn capacity(&self) -> Option<u8> {
+        fs::read_to_string(self.sysfs_dir.join("capacity"))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    fn status(&self) -> Option<String> {
+        Some(fs::read_to_string(self.sysfs_dir.join("status")).ok()?.trim().to_string())
+    }
+}
+
+impl Module for Battery {
+    fn render(&mut self) -> Block {
+        let capacity = self.capacity();
+        let text = match capacity {
+            Some(capacity) => match self.status() {
+                Some(status) => format!("{} {}%", status, capacity),
+                None => format!("{}%", capacity),
+            },
+            None => "N/A".to_string(),
+        };
+        let mut block = Block::new(text);
+        block.with_seperator();
+        block.name("battery".to_string(), self.sysfs_dir.display().to_string());
+        if capacity.is_some_and(|capacity| capacity < LOW_BATTERY_THRESHOLD) {
+            block.color(Color::named("red").unwrap());
+        }
+        block
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(30)
+    }
+}
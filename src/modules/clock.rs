@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use chrono::Local;
+
+use crate::protocol::{button, Block, ClientEvent, MinWidth};
+use crate::status_bar::Module;
+
+/// Shows the current time, or the current date when clicked.
+pub struct Clock {
+    showing_date: bool,
+}
+
+impl Clock {
+    pub fn new() -> Self {
+        Self {
+            showing_date: false,
+        }
+    }
+}
+
+impl Module for Clock {
+    fn render(&mut self) -> Block {
+        let format = if self.showing_date {
+            "%Y.%m.%d"
+        } else {
+            "%H:%M:%S"
+        };
+        let mut block = Block::new(Local::now().format(format).to_string());
+        block.with_seperator();
+        block.name("clock".to_string(), "local".to_string());
+        // reserve the width of the longer of the two formats, so toggling
+        // between time and date doesn't shift the rest of the bar
+        block.min_width(MinWidth::Text("0000.00.00".to_string()));
+        block
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+
+    fn id(&self) -> (&str, &str) {
+        ("clock", "local")
+    }
+
+    fn on_click(&mut self, event: &ClientEvent) {
+        if event.button == button::LEFT {
+            self.showing_date = !self.showing_date;
+        }
+    }
+}
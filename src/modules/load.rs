@@ -0,0 +1,37 @@
+use std::fs;
+use std::time::Duration;
+
+use crate::protocol::Block;
+use crate::status_bar::Module;
+
+/// Shows the 1/5/15-minute load averages reported by `/proc/loadavg`.
+pub struct LoadAverage;
+
+impl LoadAverage {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn read() -> Option<String> {
+        let contents = fs::read_to_string("/proc/loadavg").ok()?;
+        let mut fields = contents.split_whitespace();
+        let one = fields.next()?;
+        let five = fields.next()?;
+        let fifteen = fields.next()?;
+        Some(format!("{} {} {}", one, five, fifteen))
+    }
+}
+
+impl Module for LoadAverage {
+    fn render(&mut self) -> Block {
+        let text = Self::read().unwrap_or_else(|| "N/A".to_string());
+        let mut block = Block::new(text);
+        block.with_seperator();
+        block.name("load".to_string(), "avg".to_string());
+        block
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(5)
+    }
+}
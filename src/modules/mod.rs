@@ -0,0 +1,9 @@
+mod battery;
+mod clock;
+mod load;
+mod network;
+
+pub use battery::Battery;
+pub use clock::Clock;
+pub use load::LoadAverage;
+pub use network::NetworkThroughput;
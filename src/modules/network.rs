@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use crate::protocol::{Block, MinWidth};
+use crate::status_bar::Module;
+
+/// The "Block Elements" glyphs used for the sparkline, from empty (index 0)
+/// up to full (index 8).
+const SPARKLINE_GLYPHS: [char; 9] = [
+    ' ', '\u{2581}', '\u{2582}', '\u{2583}', '\u{2584}', '\u{2585}', '\u{2586}', '\u{2587}',
+    '\u{2588}',
+];
+
+/// Renders a live bandwidth sparkline for a network interface, reading
+/// cumulative byte counters from `/sys/class/net/<iface>/statistics`.
+pub struct NetworkThroughput {
+    iface: String,
+    /// The rate (bytes/s) that maps to a full bar; rates above this clamp.
+    max_bytes: f64,
+    /// Number of sparkline samples to keep (most-recent on the right).
+    width: usize,
+    samples: VecDeque<u64>,
+    last_counters: Option<(u64, u64)>,
+    last_sample_at: Instant,
+    min_width_px: u32,
+}
+
+impl NetworkThroughput {
+    pub fn new(iface: impl Into<String>, max_bytes: f64) -> Self {
+        let width = 10;
+        Self {
+            iface: iface.into(),
+            max_bytes,
+            width,
+            samples: VecDeque::from(vec![0; width]),
+            last_counters: None,
+            last_sample_at: Instant::now(),
+            min_width_px: 0,
+        }
+    }
+
+    /// Reserves a fixed pixel width for the block so the numeric readout
+    /// doesn't jitter as its digit count changes.
+    pub fn netdev_width(mut self, pixels: u32) -> Self {
+        self.min_width_px = pixels;
+        self
+    }
+
+    fn read_counters(&self) -> Option<(u64, u64)> {
+        let stats = format!("/sys/class/net/{}/statistics", self.iface);
+        let rx = fs::read_to_string(format!("{}/rx_bytes", stats))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        let tx = fs::read_to_string(format!("{}/tx_bytes", stats))
+            .ok()?
+            .trim()
+            .parse()
+            .ok()?;
+        Some((rx, tx))
+    }
+
+    fn glyph(&self, value: u64) -> char {
+        let ratio = (value as f64 / self.max_bytes).clamp(0.0, 1.0);
+        let idx = (ratio * 8.0).round() as usize;
+        SPARKLINE_GLYPHS[idx.min(8)]
+    }
+
+    /// Computes the max of the down/up rate (bytes/s) since the last sample,
+    /// advancing the stored counters.
+    fn sample_rate(&mut self, elapsed_secs: f64) -> u64 {
+        let counters = self.read_counters();
+        match (counters, self.last_counters) {
+            (Some((rx, tx)), Some((last_rx, last_tx))) => {
+                self.last_counters = Some((rx, tx));
+                let down = (rx.saturating_sub(last_rx) as f64 / elapsed_secs) as u64;
+                let up = (tx.saturating_sub(last_tx) as f64 / elapsed_secs) as u64;
+                down.max(up)
+            }
+            (Some(counters), None) => {
+                self.last_counters = Some(counters);
+                0
+            }
+            (None, _) => 0,
+        }
+    }
+
+    fn format_rate(bytes_per_sec: u64) -> String {
+        const KIB: f64 = 1024.0;
+        const MIB: f64 = KIB * 1024.0;
+        let bytes_per_sec = bytes_per_sec as f64;
+        if bytes_per_sec >= MIB {
+            format!("{:.1}MiB/s", bytes_per_sec / MIB)
+        } else if bytes_per_sec >= KIB {
+            format!("{:.1}KiB/s", bytes_per_sec / KIB)
+        } else {
+            format!("{}B/s", bytes_per_sec as u64)
+        }
+    }
+}
+
+impl Module for NetworkThroughput {
+    fn render(&mut self) -> Block {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_sample_at).as_secs_f64().max(1e-6);
+        self.last_sample_at = now;
+
+        let rate = self.sample_rate(elapsed);
+
+        self.samples.push_back(rate);
+        if self.samples.len() > self.width {
+            self.samples.pop_front();
+        }
+
+        let sparkline: String = self.samples.iter().map(|&v| self.glyph(v)).collect();
+        let text = format!("{} {}", sparkline, Self::format_rate(rate));
+
+        let mut block = Block::new(text);
+        block.with_seperator();
+        block.name("network".to_string(), self.iface.clone());
+        if self.min_width_px > 0 {
+            block.min_width(MinWidth::Pixels(self.min_width_px));
+        }
+        block
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(1)
+    }
+}
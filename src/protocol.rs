@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+
+use crate::color::Color;
+
+/// The  header  is  a  JSON object with support for the following
+/// properties (only version is required)
+#[derive(Serialize, Deserialize)]
+pub struct Header {
+    ///The protocol version to use. Currently, this must be 1
+    version: u8,
+
+    /// Whether to receive click event information to standard input
+    #[serde(skip_serializing_if = "Option::is_none")]
+    click_events: Option<bool>,
+
+    /// The signal that swaybar should send to continue processing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    const_signal: Option<u32>,
+
+    /// The signal that swaybar should send to stop processing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_signal: Option<u32>,
+}
+
+impl Header {
+    pub fn new(version: u8) -> Self {
+        Self {
+            version,
+            click_events: Option::None,
+            const_signal: Option::None,
+            stop_signal: Option::None,
+        }
+    }
+
+    pub fn with_click_events(mut self) -> Self {
+        self.click_events = Some(true);
+        self
+    }
+}
+
+/// The body is an infinite array, where each element of the array
+/// is a representation of the status line at the  time  that  the
+/// element  was  written.  Each element of the array is itself an
+/// array of JSON objects, where each object represents a block in
+/// the status line. Each block can have the following  properties
+/// (only full_text is required)
+#[derive(Serialize, Deserialize, Clone)]
+pub struct Block {
+    /// The text that will be displayed. If missing, the block will be skipped.
+    pub full_text: String,
+
+    /// If given and the text needs to be shortened due to space, this will be displayed instead of full_text
+    #[serde(skip_serializing_if = "Option::is_none")]
+    short_text: Option<String>,
+
+    /// A name for the block. This is only used to identify the block for click events. If set, each block should have a unique name and instance pair.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+
+    /// The text color to use
+    #[serde(skip_serializing_if = "Option::is_none")]
+    color: Option<Color>,
+
+    /// The background color for the block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<Color>,
+
+    /// The border color for the block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border: Option<Color>,
+
+    /// The height in pixels of the top border. The default is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_top: Option<u8>,
+
+    /// The width in pixels of the right border. The default is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_right: Option<u8>,
+
+    /// The height in pixels of the bottom border. The default is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_bottom: Option<u8>,
+
+    /// The width in pixels of the left border. The default is 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    border_left: Option<u8>,
+
+    /// The minimum width to use for the block. This can either be given in pixels
+    /// or a string can be given to allow for it to be calculated based on the width of the string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_width: Option<MinWidth>,
+
+    /// If the text does not span the full width of the block,
+    /// this specifies how the text should be aligned inside of the block. This can be left (default),
+    /// right, or center.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    align: Option<String>,
+
+    /// The instance of the name for the block. This is only used to identify the block for click events.
+    /// If set, each block should have a unique name and instance pair.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instance: Option<String>,
+
+    /// Whether the block should be displayed as urgent.
+    /// Currently swaybar utilizes the colors set in the sway config for urgent workspace buttons.
+    /// See sway-bar(5) for more information on sway bar color con‐ figuration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    urgent: Option<bool>,
+
+    /// Whether the bar separator should be drawn after the block.
+    /// See sway-bar(5) for more information on how to set the separator text.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seperator: Option<bool>,
+
+    /// The amount of pixels to leave blank after the block.
+    /// The separator text will be displayed cen‐ tered in this gap. The default is 9 pixels.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seperator_block_width: Option<bool>,
+
+    /// The type of markup to use when parsing the text for the block.
+    /// This can either be pango or none (default).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    markup: Option<String>,
+}
+
+impl Block {
+    pub fn new(full_text: String) -> Self {
+        Self {
+            full_text,
+            short_text: Option::None,
+            name: Option::None,
+            color: Option::None,
+            background: Option::None,
+            border: Option::None,
+            border_top: Option::None,
+            border_right: Option::None,
+            border_bottom: Option::None,
+            border_left: Option::None,
+            min_width: Option::None,
+            align: Option::None,
+            instance: Option::None,
+            urgent: Option::None,
+            seperator: Option::None,
+            seperator_block_width: Option::None,
+            markup: Option::None,
+        }
+    }
+
+    pub fn color(&mut self, color: Color) {
+        self.color = Some(color);
+    }
+
+    pub fn background(&mut self, background: Color) {
+        self.background = Some(background);
+    }
+
+    pub fn border(&mut self, border: Color) {
+        self.border = Some(border);
+    }
+
+    pub fn with_seperator(&mut self) {
+        self.seperator = Some(true);
+    }
+
+    pub fn name(&mut self, name: String, instance: String) {
+        self.name = Some(name);
+        self.instance = Some(instance);
+    }
+
+    pub fn min_width(&mut self, min_width: MinWidth) {
+        self.min_width = Some(min_width);
+    }
+}
+
+/// The minimum width to reserve for a block: either a fixed pixel count, or
+/// a sample string whose rendered width is used instead. The latter is how
+/// you pin a block's layout to a representative value (e.g. `"100%"` or
+/// `"00:00:00"`) so it doesn't shift as its actual text's width changes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MinWidth {
+    Pixels(u32),
+    Text(String),
+}
+
+/// If requested in the header, swaybar will write a JSON object, that can be read from standard  in,  when  the
+/// user clicks on a block. The event object will have the following properties:
+#[derive(Serialize, Deserialize)]
+pub struct ClientEvent {
+    pub name: String,
+    pub instance: String,
+    pub x: u32,
+    pub y: u32,
+    pub button: u32,
+    pub event: u32,
+    pub relative_x: u32,
+    pub relative_y: u32,
+    pub width: u32,
+    pub height: u32,
+
+    /// The modifier keys (e.g. "Shift", "Mod1") held during the click, if any.
+    #[serde(default)]
+    pub modifiers: Vec<String>,
+}
+
+/// Known `button` values of a [`ClientEvent`].
+pub mod button {
+    pub const LEFT: u32 = 1;
+    pub const MIDDLE: u32 = 2;
+    pub const RIGHT: u32 = 3;
+    pub const SCROLL_UP: u32 = 4;
+    pub const SCROLL_DOWN: u32 = 5;
+}
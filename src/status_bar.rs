@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, Mutex};
+
+use crate::protocol::{Block, ClientEvent};
+
+/// A single block of the status line. Implementors decide how they render
+/// themselves, how often they'd like to be refreshed, and (optionally) how
+/// they react to being clicked.
+pub trait Module: Send {
+    /// Renders the module's current state into a `Block` for this tick.
+    fn render(&mut self) -> Block;
+
+    /// How often this module would like to be refreshed. Each module ticks
+    /// on its own schedule, independently of every other module.
+    fn interval(&self) -> Duration;
+
+    /// The `(name, instance)` pair this module's blocks are identified by,
+    /// used to route click events back to it. Modules that don't need click
+    /// handling can leave this as the default, which never matches.
+    fn id(&self) -> (&str, &str) {
+        ("", "")
+    }
+
+    /// Reacts to a click event targeted at this module's block.
+    fn on_click(&mut self, _event: &ClientEvent) {}
+}
+
+/// One slot in the status line: the module that owns it, and the block it
+/// last rendered.
+struct Slot {
+    module: Arc<Mutex<Box<dyn Module>>>,
+    block: Arc<Mutex<Block>>,
+}
+
+/// Assembles a set of independently-scheduled modules into a single status
+/// line, requesting a redraw whenever any module updates or is clicked.
+pub struct StatusBar {
+    slots: Vec<Slot>,
+    redraw_tx: mpsc::Sender<()>,
+}
+
+impl StatusBar {
+    /// Renders every module once, then spawns one refresh task per module
+    /// that ticks at that module's own `interval()`. Returns the `StatusBar`
+    /// plus the receiving half of the channel a redraw is requested on.
+    pub fn spawn(modules: Vec<Box<dyn Module>>) -> (Self, mpsc::Receiver<()>) {
+        let (redraw_tx, redraw_rx) = mpsc::channel(modules.len().max(1));
+        let mut slots = Vec::with_capacity(modules.len());
+
+        for mut module in modules {
+            let initial = module.render();
+            let interval = module.interval();
+            let module = Arc::new(Mutex::new(module));
+            let block = Arc::new(Mutex::new(initial));
+            slots.push(Slot {
+                module: Arc::clone(&module),
+                block: Arc::clone(&block),
+            });
+
+            let redraw_tx = redraw_tx.clone();
+            tokio::spawn(async move {
+                let mut ticker = tokio::time::interval(interval);
+                ticker.tick().await; // the first tick fires immediately; we already rendered once above
+                loop {
+                    ticker.tick().await;
+                    let rendered = module.lock().await.render();
+                    *block.lock().await = rendered;
+                    if redraw_tx.send(()).await.is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+
+        (Self { slots, redraw_tx }, redraw_rx)
+    }
+
+    /// Serializes the current block for every module as one status line.
+    pub async fn render(&self) -> String {
+        let mut blocks = Vec::with_capacity(self.slots.len());
+        for slot in &self.slots {
+            blocks.push(slot.block.lock().await.clone());
+        }
+        serde_json::to_string(&blocks).unwrap()
+    }
+
+    /// Routes a click event to the module whose `id()` matches it, re-renders
+    /// its block, and requests a redraw.
+    pub async fn dispatch_click(&self, event: &ClientEvent) {
+        for slot in &self.slots {
+            let mut module = slot.module.lock().await;
+            if module.id() == (event.name.as_str(), event.instance.as_str()) {
+                module.on_click(event);
+                *slot.block.lock().await = module.render();
+                let _ = self.redraw_tx.send(()).await;
+                return;
+            }
+        }
+    }
+}